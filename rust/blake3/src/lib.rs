@@ -30,6 +30,27 @@ pub fn blake3_mac(key: &[u8], data: &[u8]) -> Result<Vec<u8>, String> {
     Ok(hasher.finalize().as_bytes().to_vec())
 }
 
+/// One-shot extendable-output hash: derive `len` bytes from `data`.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn blake3_xof(data: &[u8], len: usize) -> Vec<u8> {
+    let mut hasher = Hasher::new();
+    hasher.update(data);
+    let mut out = vec![0u8; len];
+    hasher.finalize_xof().fill(&mut out);
+    out
+}
+
+/// One-shot key derivation: derive `len` bytes of key material from
+/// `key_material` under BLAKE3's `derive_key` mode for the given `context`.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn blake3_derive_key(context: &str, key_material: &[u8], len: usize) -> Vec<u8> {
+    let mut hasher = Hasher::new_derive_key(context);
+    hasher.update(key_material);
+    let mut out = vec![0u8; len];
+    hasher.finalize_xof().fill(&mut out);
+    out
+}
+
 // ---------------------------------------------------------------------------
 // Streaming hasher
 // ---------------------------------------------------------------------------
@@ -59,6 +80,13 @@ impl Blake3Hasher {
         })
     }
 
+    /// Create a new hasher in key-derivation mode for the given `context`.
+    pub fn new_derive_key(context: &str) -> Self {
+        Blake3Hasher {
+            inner: Hasher::new_derive_key(context),
+        }
+    }
+
     /// Feed data into the hasher. Can be called multiple times.
     pub fn update(&mut self, data: &[u8]) {
         self.inner.update(data);
@@ -75,6 +103,14 @@ impl Blake3Hasher {
     pub fn reset(&mut self) {
         self.inner.reset();
     }
+
+    /// Finalize and return `len` bytes of extendable output.
+    /// The hasher state is NOT consumed, mirroring `finalize`.
+    pub fn finalize_xof(&self, len: usize) -> Vec<u8> {
+        let mut out = vec![0u8; len];
+        self.inner.finalize_xof().fill(&mut out);
+        out
+    }
 }
 
 impl Default for Blake3Hasher {
@@ -83,6 +119,24 @@ impl Default for Blake3Hasher {
     }
 }
 
+// ---------------------------------------------------------------------------
+// std::io::Write
+// ---------------------------------------------------------------------------
+
+/// Feeds written bytes straight into the hasher, so callers can
+/// `std::io::copy` a reader into it instead of manually chunking.
+#[cfg(not(feature = "wasm"))]
+impl std::io::Write for Blake3Hasher {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -202,4 +256,78 @@ mod tests {
         let h3 = hasher.finalize();
         assert_ne!(h1, h3);
     }
+
+    #[test]
+    fn test_xof_default_len_matches_finalize() {
+        let data = b"test input";
+        let xof = blake3_xof(data, 32);
+        let finalize = blake3_hash(data);
+        assert_eq!(xof, finalize);
+    }
+
+    #[test]
+    fn test_xof_extended_len_extends_default() {
+        let data = b"test input";
+        let short = blake3_xof(data, 32);
+        let long = blake3_xof(data, 64);
+        assert_eq!(&long[..32], short.as_slice());
+    }
+
+    #[test]
+    fn test_hasher_finalize_xof_matches_oneshot() {
+        let data = b"hello world, this is a streaming test with blake3";
+
+        let mut hasher = Blake3Hasher::new();
+        hasher.update(data);
+        let streamed = hasher.finalize_xof(48);
+
+        let oneshot = blake3_xof(data, 48);
+        assert_eq!(streamed, oneshot);
+    }
+
+    #[test]
+    fn test_derive_key_is_deterministic() {
+        let a = blake3_derive_key("hashbuf test context", b"key material", 32);
+        let b = blake3_derive_key("hashbuf test context", b"key material", 32);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_derive_key_context_changes_output() {
+        let a = blake3_derive_key("context a", b"key material", 32);
+        let b = blake3_derive_key("context b", b"key material", 32);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_hasher_derive_key_matches_oneshot() {
+        let mut hasher = Blake3Hasher::new_derive_key("hashbuf test context");
+        hasher.update(b"key material");
+        let streamed = hasher.finalize_xof(32);
+
+        let oneshot = blake3_derive_key("hashbuf test context", b"key material", 32);
+        assert_eq!(streamed, oneshot);
+    }
+
+    #[test]
+    fn test_io_copy_matches_oneshot() {
+        use std::io::Write;
+
+        let data = b"hash large files or network streams via io::copy";
+        let mut hasher = Blake3Hasher::new();
+        std::io::copy(&mut &data[..], &mut hasher).unwrap();
+
+        assert_eq!(hasher.finalize(), blake3_hash(data));
+    }
+
+    #[test]
+    fn test_write_returns_bytes_written() {
+        use std::io::Write;
+
+        let mut hasher = Blake3Hasher::new();
+        let n = hasher.write(b"abc").unwrap();
+        assert_eq!(n, 3);
+        hasher.flush().unwrap();
+        assert_eq!(hasher.finalize(), blake3_hash(b"abc"));
+    }
 }
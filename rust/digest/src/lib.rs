@@ -0,0 +1,280 @@
+use md5::Md5;
+use ripemd::Ripemd160;
+use sha1::Sha1;
+use sha2::{Digest as _, Sha224, Sha256, Sha384, Sha512};
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+// ---------------------------------------------------------------------------
+// Algorithm selector
+// ---------------------------------------------------------------------------
+
+/// Runtime-selectable digest algorithm, modeled on OpenSSL's `MessageDigest`.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    Sha256,
+    Sha224,
+    Sha384,
+    Sha512,
+    Sha1,
+    Md5,
+    Ripemd160,
+    Blake3,
+}
+
+impl Algorithm {
+    /// Output length in bytes produced by this algorithm.
+    pub fn output_len(self) -> usize {
+        match self {
+            Algorithm::Md5 => 16,
+            Algorithm::Sha1 | Algorithm::Ripemd160 => 20,
+            Algorithm::Sha224 => 28,
+            Algorithm::Sha256 | Algorithm::Blake3 => 32,
+            Algorithm::Sha384 => 48,
+            Algorithm::Sha512 => 64,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// One-shot dispatch
+// ---------------------------------------------------------------------------
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn digest(algo: Algorithm, data: &[u8]) -> Vec<u8> {
+    match algo {
+        Algorithm::Sha256 => {
+            let mut hasher = Sha256::new();
+            hasher.update(data);
+            hasher.finalize().to_vec()
+        }
+        Algorithm::Sha224 => {
+            let mut hasher = Sha224::new();
+            hasher.update(data);
+            hasher.finalize().to_vec()
+        }
+        Algorithm::Sha384 => {
+            let mut hasher = Sha384::new();
+            hasher.update(data);
+            hasher.finalize().to_vec()
+        }
+        Algorithm::Sha512 => {
+            let mut hasher = Sha512::new();
+            hasher.update(data);
+            hasher.finalize().to_vec()
+        }
+        Algorithm::Sha1 => {
+            let mut hasher = Sha1::new();
+            hasher.update(data);
+            hasher.finalize().to_vec()
+        }
+        Algorithm::Md5 => {
+            let mut hasher = Md5::new();
+            hasher.update(data);
+            hasher.finalize().to_vec()
+        }
+        Algorithm::Ripemd160 => {
+            let mut hasher = Ripemd160::new();
+            hasher.update(data);
+            hasher.finalize().to_vec()
+        }
+        Algorithm::Blake3 => blake3::hash(data).as_bytes().to_vec(),
+    }
+}
+
+/// One-shot dispatch returning a hex string.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn digest_hex(algo: Algorithm, data: &[u8]) -> String {
+    hex::encode(digest(algo, data))
+}
+
+// ---------------------------------------------------------------------------
+// Streaming hasher
+// ---------------------------------------------------------------------------
+
+enum Inner {
+    Sha256(Sha256),
+    Sha224(Sha224),
+    Sha384(Sha384),
+    Sha512(Sha512),
+    Sha1(Sha1),
+    Md5(Md5),
+    Ripemd160(Ripemd160),
+    Blake3(Box<blake3::Hasher>),
+}
+
+impl Inner {
+    fn new(algo: Algorithm) -> Self {
+        match algo {
+            Algorithm::Sha256 => Inner::Sha256(Sha256::new()),
+            Algorithm::Sha224 => Inner::Sha224(Sha224::new()),
+            Algorithm::Sha384 => Inner::Sha384(Sha384::new()),
+            Algorithm::Sha512 => Inner::Sha512(Sha512::new()),
+            Algorithm::Sha1 => Inner::Sha1(Sha1::new()),
+            Algorithm::Md5 => Inner::Md5(Md5::new()),
+            Algorithm::Ripemd160 => Inner::Ripemd160(Ripemd160::new()),
+            Algorithm::Blake3 => Inner::Blake3(Box::new(blake3::Hasher::new())),
+        }
+    }
+}
+
+/// Streaming hasher that dispatches to a concrete algorithm chosen at runtime.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct DynHasher {
+    algo: Algorithm,
+    inner: Inner,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl DynHasher {
+    /// Create a new hasher for the given algorithm.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new(algo: Algorithm) -> Self {
+        DynHasher {
+            algo,
+            inner: Inner::new(algo),
+        }
+    }
+
+    /// Feed data into the hasher. Can be called multiple times.
+    pub fn update(&mut self, data: &[u8]) {
+        match &mut self.inner {
+            Inner::Sha256(h) => h.update(data),
+            Inner::Sha224(h) => h.update(data),
+            Inner::Sha384(h) => h.update(data),
+            Inner::Sha512(h) => h.update(data),
+            Inner::Sha1(h) => h.update(data),
+            Inner::Md5(h) => h.update(data),
+            Inner::Ripemd160(h) => h.update(data),
+            Inner::Blake3(h) => {
+                h.update(data);
+            }
+        }
+    }
+
+    /// Finalize and return the digest. The hasher state is NOT consumed —
+    /// you can continue calling `update` after `finalize`.
+    pub fn finalize(&self) -> Vec<u8> {
+        match &self.inner {
+            Inner::Sha256(h) => h.clone().finalize().to_vec(),
+            Inner::Sha224(h) => h.clone().finalize().to_vec(),
+            Inner::Sha384(h) => h.clone().finalize().to_vec(),
+            Inner::Sha512(h) => h.clone().finalize().to_vec(),
+            Inner::Sha1(h) => h.clone().finalize().to_vec(),
+            Inner::Md5(h) => h.clone().finalize().to_vec(),
+            Inner::Ripemd160(h) => h.clone().finalize().to_vec(),
+            Inner::Blake3(h) => h.finalize().as_bytes().to_vec(),
+        }
+    }
+
+    /// Reset the hasher to its initial state.
+    pub fn reset(&mut self) {
+        self.inner = Inner::new(self.algo);
+    }
+
+    /// The algorithm this hasher was constructed with.
+    pub fn algorithm(&self) -> Algorithm {
+        self.algo
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex::encode;
+
+    #[test]
+    fn test_output_len_matches_digest_len() {
+        for algo in [
+            Algorithm::Sha256,
+            Algorithm::Sha224,
+            Algorithm::Sha384,
+            Algorithm::Sha512,
+            Algorithm::Sha1,
+            Algorithm::Md5,
+            Algorithm::Ripemd160,
+            Algorithm::Blake3,
+        ] {
+            assert_eq!(digest(algo, b"abc").len(), algo.output_len());
+        }
+    }
+
+    #[test]
+    fn test_sha256_matches_known_vector() {
+        let result = digest(Algorithm::Sha256, b"abc");
+        assert_eq!(
+            encode(&result),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_blake3_matches_known_vector() {
+        let result = digest(Algorithm::Blake3, b"");
+        assert_eq!(
+            encode(&result),
+            "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"
+        );
+    }
+
+    #[test]
+    fn test_digest_hex_matches_digest() {
+        let hex = digest_hex(Algorithm::Sha1, b"abc");
+        assert_eq!(hex, encode(digest(Algorithm::Sha1, b"abc")));
+    }
+
+    #[test]
+    fn test_dyn_hasher_matches_oneshot() {
+        for algo in [
+            Algorithm::Sha256,
+            Algorithm::Sha224,
+            Algorithm::Sha384,
+            Algorithm::Sha512,
+            Algorithm::Sha1,
+            Algorithm::Md5,
+            Algorithm::Ripemd160,
+            Algorithm::Blake3,
+        ] {
+            let oneshot = digest(algo, b"hello world");
+
+            let mut hasher = DynHasher::new(algo);
+            hasher.update(b"hello");
+            hasher.update(b" world");
+            let streamed = hasher.finalize();
+
+            assert_eq!(oneshot, streamed);
+        }
+    }
+
+    #[test]
+    fn test_dyn_hasher_finalize_no_consume() {
+        let mut hasher = DynHasher::new(Algorithm::Sha256);
+        hasher.update(b"abc");
+        let h1 = hasher.finalize();
+        let h2 = hasher.finalize();
+        assert_eq!(h1, h2);
+    }
+
+    #[test]
+    fn test_dyn_hasher_reset() {
+        let mut hasher = DynHasher::new(Algorithm::Md5);
+        hasher.update(b"garbage");
+        hasher.reset();
+        hasher.update(b"abc");
+        let result = hasher.finalize();
+        let expected = digest(Algorithm::Md5, b"abc");
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_dyn_hasher_algorithm() {
+        let hasher = DynHasher::new(Algorithm::Ripemd160);
+        assert_eq!(hasher.algorithm(), Algorithm::Ripemd160);
+    }
+}
@@ -0,0 +1,216 @@
+use hashbuf_sha256::double_sha256_hash;
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+const HASH_LEN: usize = 32;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn check_leaves(leaves: &[Vec<u8>]) -> Result<(), String> {
+    if leaves.is_empty() {
+        return Err("cannot build a Merkle tree from an empty leaf set".to_string());
+    }
+    for leaf in leaves {
+        if leaf.len() != HASH_LEN {
+            return Err(format!("leaf must be {} bytes, got {}", HASH_LEN, leaf.len()));
+        }
+    }
+    Ok(())
+}
+
+fn combine(left: &[u8], right: &[u8]) -> Vec<u8> {
+    let mut pair = Vec::with_capacity(left.len() + right.len());
+    pair.extend_from_slice(left);
+    pair.extend_from_slice(right);
+    double_sha256_hash(&pair)
+}
+
+// ---------------------------------------------------------------------------
+// Merkle root
+// ---------------------------------------------------------------------------
+
+/// Compute a Bitcoin-compatible Merkle root over 32-byte leaf hashes.
+/// A lone leaf is returned unchanged; an odd node at any level is
+/// duplicated before pairing.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn merkle_root(leaves: &[Vec<u8>]) -> Result<Vec<u8>, String> {
+    check_leaves(leaves)?;
+
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        if level.len() % 2 == 1 {
+            let last = level.last().unwrap().clone();
+            level.push(last);
+        }
+        level = level
+            .chunks(2)
+            .map(|pair| combine(&pair[0], &pair[1]))
+            .collect();
+    }
+
+    Ok(level.remove(0))
+}
+
+// ---------------------------------------------------------------------------
+// Inclusion proofs
+// ---------------------------------------------------------------------------
+
+/// One step of a Merkle inclusion proof: the sibling hash and which side
+/// it sits on relative to the node being proven.
+#[cfg_attr(feature = "wasm", wasm_bindgen(getter_with_clone))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProofStep {
+    pub sibling: Vec<u8>,
+    pub sibling_is_left: bool,
+}
+
+/// Build the sibling path needed to prove that `leaves[index]` is included
+/// in the tree's root.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn merkle_proof(leaves: &[Vec<u8>], index: usize) -> Result<Vec<MerkleProofStep>, String> {
+    check_leaves(leaves)?;
+    if index >= leaves.len() {
+        return Err(format!(
+            "index {} out of bounds for {} leaves",
+            index,
+            leaves.len()
+        ));
+    }
+
+    let mut proof = Vec::new();
+    let mut level = leaves.to_vec();
+    let mut idx = index;
+
+    while level.len() > 1 {
+        if level.len() % 2 == 1 {
+            let last = level.last().unwrap().clone();
+            level.push(last);
+        }
+
+        let sibling_idx = if idx % 2 == 0 { idx + 1 } else { idx - 1 };
+        proof.push(MerkleProofStep {
+            sibling: level[sibling_idx].clone(),
+            sibling_is_left: idx % 2 == 1,
+        });
+
+        level = level
+            .chunks(2)
+            .map(|pair| combine(&pair[0], &pair[1]))
+            .collect();
+        idx /= 2;
+    }
+
+    Ok(proof)
+}
+
+/// Verify that `leaf`, combined with `proof`, reproduces `root` — an
+/// SPV-style inclusion check that doesn't need the full leaf set.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn verify_merkle_proof(leaf: &[u8], proof: &[MerkleProofStep], root: &[u8]) -> bool {
+    let mut current = leaf.to_vec();
+    for step in proof {
+        current = if step.sibling_is_left {
+            combine(&step.sibling, &current)
+        } else {
+            combine(&current, &step.sibling)
+        };
+    }
+    current == root
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex::encode;
+
+    fn leaf(data: &[u8]) -> Vec<u8> {
+        double_sha256_hash(data)
+    }
+
+    #[test]
+    fn test_empty_leaves_is_error() {
+        assert!(merkle_root(&[]).is_err());
+    }
+
+    #[test]
+    fn test_single_leaf_returned_unchanged() {
+        let a = leaf(b"a");
+        assert_eq!(merkle_root(&[a.clone()]).unwrap(), a);
+    }
+
+    #[test]
+    fn test_two_leaves() {
+        let a = leaf(b"a");
+        let b = leaf(b"b");
+        let expected = combine(&a, &b);
+        assert_eq!(merkle_root(&[a, b]).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_odd_leaf_count_duplicates_last() {
+        let a = leaf(b"a");
+        let b = leaf(b"b");
+        let c = leaf(b"c");
+        let top_left = combine(&a, &b);
+        let top_right = combine(&c, &c);
+        let expected = combine(&top_left, &top_right);
+        assert_eq!(merkle_root(&[a, b, c]).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_wrong_length_leaf_is_error() {
+        let short = vec![0u8; 16];
+        assert!(merkle_root(&[short]).is_err());
+    }
+
+    #[test]
+    fn test_known_four_leaf_root() {
+        let leaves: Vec<Vec<u8>> = vec![leaf(b"a"), leaf(b"b"), leaf(b"c"), leaf(b"d")];
+        let root = merkle_root(&leaves).unwrap();
+        // Recomputed independently via pairwise double-sha256.
+        let top_left = combine(&leaves[0], &leaves[1]);
+        let top_right = combine(&leaves[2], &leaves[3]);
+        let expected = combine(&top_left, &top_right);
+        assert_eq!(encode(&root), encode(&expected));
+    }
+
+    #[test]
+    fn test_proof_for_single_leaf_is_empty() {
+        let a = leaf(b"a");
+        let proof = merkle_proof(&[a.clone()], 0).unwrap();
+        assert!(proof.is_empty());
+        assert!(verify_merkle_proof(&a, &proof, &a));
+    }
+
+    #[test]
+    fn test_proof_roundtrips_for_every_index() {
+        let leaves: Vec<Vec<u8>> = vec![leaf(b"a"), leaf(b"b"), leaf(b"c"), leaf(b"d"), leaf(b"e")];
+        let root = merkle_root(&leaves).unwrap();
+
+        for (i, l) in leaves.iter().enumerate() {
+            let proof = merkle_proof(&leaves, i).unwrap();
+            assert!(verify_merkle_proof(l, &proof, &root));
+        }
+    }
+
+    #[test]
+    fn test_proof_rejects_wrong_leaf() {
+        let leaves: Vec<Vec<u8>> = vec![leaf(b"a"), leaf(b"b"), leaf(b"c")];
+        let root = merkle_root(&leaves).unwrap();
+        let proof = merkle_proof(&leaves, 0).unwrap();
+        assert!(!verify_merkle_proof(&leaf(b"x"), &proof, &root));
+    }
+
+    #[test]
+    fn test_proof_out_of_bounds_is_error() {
+        let leaves = vec![leaf(b"a"), leaf(b"b")];
+        assert!(merkle_proof(&leaves, 2).is_err());
+    }
+}
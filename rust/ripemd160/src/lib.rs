@@ -0,0 +1,80 @@
+use hashbuf_sha256::sha256_hash;
+use ripemd::{Digest, Ripemd160};
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+// ---------------------------------------------------------------------------
+// One-shot functions
+// ---------------------------------------------------------------------------
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn ripemd160_hash(data: &[u8]) -> Vec<u8> {
+    let mut hasher = Ripemd160::new();
+    hasher.update(data);
+    hasher.finalize().to_vec()
+}
+
+/// One-shot RIPEMD-160 hash returning hex string.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn ripemd160_hex(data: &[u8]) -> String {
+    let mut hasher = Ripemd160::new();
+    hasher.update(data);
+    hex::encode(hasher.finalize())
+}
+
+/// Bitcoin-style `hash160`: RIPEMD-160 of the SHA-256 of `data`, as used to
+/// derive P2PKH-style addresses.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn hash160(data: &[u8]) -> Vec<u8> {
+    ripemd160_hash(&sha256_hash(data))
+}
+
+/// `hash160`, returning hex string.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn hash160_hex(data: &[u8]) -> String {
+    hex::encode(hash160(data))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex::encode;
+
+    #[test]
+    fn test_ripemd160_empty() {
+        let expected = "9c1185a5c5e9fc54612808977ee8f548b2258d31";
+        let result = ripemd160_hash(b"");
+        assert_eq!(encode(&result), expected);
+    }
+
+    #[test]
+    fn test_ripemd160_abc() {
+        let expected = "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc";
+        let result = ripemd160_hash(b"abc");
+        assert_eq!(encode(&result), expected);
+    }
+
+    #[test]
+    fn test_ripemd160_hex() {
+        let hex = ripemd160_hex(b"abc");
+        assert_eq!(hex, "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc");
+    }
+
+    #[test]
+    fn test_hash160_matches_manual_composition() {
+        let data = b"abc";
+        let expected = ripemd160_hash(&sha256_hash(data));
+        assert_eq!(hash160(data), expected);
+    }
+
+    #[test]
+    fn test_hash160_hex_matches_hash160() {
+        let data = b"abc";
+        assert_eq!(hash160_hex(data), encode(hash160(data)));
+    }
+}
@@ -97,6 +97,24 @@ impl Default for Sha256Hasher {
     }
 }
 
+// ---------------------------------------------------------------------------
+// std::io::Write
+// ---------------------------------------------------------------------------
+
+/// Feeds written bytes straight into the hasher, so callers can
+/// `std::io::copy` a reader into it instead of manually chunking.
+#[cfg(not(feature = "wasm"))]
+impl std::io::Write for Sha256Hasher {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -247,4 +265,26 @@ mod tests {
             "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
         );
     }
+
+    #[test]
+    fn test_io_copy_matches_oneshot() {
+        use std::io::Write;
+
+        let data = b"hash large files or network streams via io::copy";
+        let mut hasher = Sha256Hasher::new();
+        std::io::copy(&mut &data[..], &mut hasher).unwrap();
+
+        assert_eq!(hasher.finalize(), sha256_hash(data));
+    }
+
+    #[test]
+    fn test_write_returns_bytes_written() {
+        use std::io::Write;
+
+        let mut hasher = Sha256Hasher::new();
+        let n = hasher.write(b"abc").unwrap();
+        assert_eq!(n, 3);
+        hasher.flush().unwrap();
+        assert_eq!(hasher.finalize(), sha256_hash(b"abc"));
+    }
 }
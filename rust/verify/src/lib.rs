@@ -0,0 +1,112 @@
+use hashbuf_blake3::blake3_mac;
+use hashbuf_sha256::sha256_hmac;
+use subtle::ConstantTimeEq;
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+// ---------------------------------------------------------------------------
+// Generic constant-time comparison
+// ---------------------------------------------------------------------------
+
+/// Compare two byte slices in constant time. Unequal lengths return `false`
+/// without short-circuiting on content, so callers validating session
+/// tokens or signatures don't reintroduce a timing side channel.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.ct_eq(b).into()
+}
+
+// ---------------------------------------------------------------------------
+// MAC/digest verification
+// ---------------------------------------------------------------------------
+
+/// Recompute the HMAC-SHA256 tag for `(key, data)` and compare it against
+/// `expected_tag` in constant time.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn verify_sha256_hmac(key: &[u8], data: &[u8], expected_tag: &[u8]) -> Result<bool, String> {
+    let tag = sha256_hmac(key, data)?;
+    Ok(constant_time_eq(&tag, expected_tag))
+}
+
+/// Recompute the keyed-BLAKE3 tag for `(key, data)` and compare it against
+/// `expected_tag` in constant time.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn verify_blake3_mac(key: &[u8], data: &[u8], expected_tag: &[u8]) -> Result<bool, String> {
+    let tag = blake3_mac(key, data)?;
+    Ok(constant_time_eq(&tag, expected_tag))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constant_time_eq_equal() {
+        assert!(constant_time_eq(b"abc", b"abc"));
+    }
+
+    #[test]
+    fn test_constant_time_eq_different_content() {
+        assert!(!constant_time_eq(b"abc", b"abd"));
+    }
+
+    #[test]
+    fn test_constant_time_eq_different_length() {
+        assert!(!constant_time_eq(b"abc", b"abcd"));
+    }
+
+    #[test]
+    fn test_verify_sha256_hmac_accepts_correct_tag() {
+        let key = b"key";
+        let data = b"message";
+        let tag = sha256_hmac(key, data).unwrap();
+        assert!(verify_sha256_hmac(key, data, &tag).unwrap());
+    }
+
+    #[test]
+    fn test_verify_sha256_hmac_rejects_wrong_tag() {
+        let key = b"key";
+        let data = b"message";
+        let mut tag = sha256_hmac(key, data).unwrap();
+        tag[0] ^= 0xff;
+        assert!(!verify_sha256_hmac(key, data, &tag).unwrap());
+    }
+
+    #[test]
+    fn test_verify_blake3_mac_accepts_correct_tag() {
+        let key = [0x42u8; 32];
+        let data = b"message";
+        let tag = blake3_mac(&key, data).unwrap();
+        assert!(verify_blake3_mac(&key, data, &tag).unwrap());
+    }
+
+    #[test]
+    fn test_verify_blake3_mac_rejects_wrong_tag() {
+        let key = [0x42u8; 32];
+        let data = b"message";
+        let mut tag = blake3_mac(&key, data).unwrap();
+        tag[0] ^= 0xff;
+        assert!(!verify_blake3_mac(&key, data, &tag).unwrap());
+    }
+
+    #[test]
+    fn test_verify_blake3_mac_propagates_key_error() {
+        assert!(verify_blake3_mac(b"short", b"message", b"tag").is_err());
+    }
+
+    #[test]
+    fn test_verify_wrong_length_tag_returns_false_not_error() {
+        let key = b"key";
+        let data = b"message";
+        let short_tag = vec![0u8; 4];
+        assert!(!verify_sha256_hmac(key, data, &short_tag).unwrap());
+    }
+}